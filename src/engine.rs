@@ -6,8 +6,8 @@ use std::collections::HashMap;
 use std::hash::Hash;
 
 /// Represents the number of rows and columns occupied by a given table cell.
-#[derive(Copy, Clone, PartialEq, Eq)]
-pub(crate) struct Span {
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
     rows: usize,
     cols: usize,
 }
@@ -18,13 +18,47 @@ impl Span {
     /// # Panics:
     ///
     /// This constructor panics if the value provided for rows or cols is zero.
-    pub(crate) fn new(rows: usize, cols: usize) -> Span {
-        if rows == 0 {
-            panic!("Error constructing Span. Zero value provided for Span.rows.")
-        } else if cols == 0 {
-            panic!("Error constructing Span. Zero value provided for Span.cols.")
+    /// Use `try_new` if you'd rather handle that case than panic.
+    pub fn new(rows: usize, cols: usize) -> Span {
+        Span::try_new(rows, cols).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Create a new Span with the specified dimensions.
+    ///
+    /// Returns `TableError::ZeroSpan` if either dimension is zero, rather
+    /// than panicking.
+    pub fn try_new(rows: usize, cols: usize) -> Result<Span, TableError> {
+        if rows == 0 || cols == 0 {
+            Err(TableError::ZeroSpan { rows, cols })
+        } else {
+            Ok(Span { rows, cols })
         }
-        Span { rows, cols }
+    }
+
+    /// Create a new Span from a `(rows, cols)` pair, as used by the CLI's
+    /// JSON span specification.
+    ///
+    /// # Panics:
+    ///
+    /// This constructor panics if either value is zero. Use `try_from_pair`
+    /// if you'd rather handle that case than panic.
+    pub fn from_pair(pair: (usize, usize)) -> Span {
+        Span::new(pair.0, pair.1)
+    }
+
+    /// Fallible version of `from_pair`.
+    pub fn try_from_pair(pair: (usize, usize)) -> Result<Span, TableError> {
+        Span::try_new(pair.0, pair.1)
+    }
+
+    /// The number of rows this cell occupies.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns this cell occupies.
+    pub fn cols(&self) -> usize {
+        self.cols
     }
 }
 
@@ -39,11 +73,53 @@ impl Default for Span {
     }
 }
 
+/// Errors that can occur while validating a `Span` or laying out a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    /// A `Span` was constructed with a zero-valued dimension.
+    ZeroSpan { rows: usize, cols: usize },
+    /// A span specification referenced a cell that never appears in the
+    /// table data.
+    UnknownCell,
+    /// Two different master cells' spans both claim the same grid
+    /// position.
+    OverlappingSpans { row: usize, col: usize },
+    /// The input rows can't be reconciled into a rectangular grid.
+    RaggedInput,
+}
+
+impl std::fmt::Display for TableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableError::ZeroSpan { rows, cols } => write!(
+                f,
+                "span dimensions must be non-zero, got {} rows and {} cols",
+                rows, cols
+            ),
+            TableError::UnknownCell => write!(
+                f,
+                "span specification references a cell that does not appear in the table"
+            ),
+            TableError::OverlappingSpans { row, col } => write!(
+                f,
+                "overlapping spans: more than one cell claims row {}, col {}",
+                row, col
+            ),
+            TableError::RaggedInput => write!(
+                f,
+                "table rows could not be reconciled into a rectangular grid"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
 /// Type alias for input table data, without span information.
-pub(crate) type TableSpec<T> = Vec<Vec<T>>;
+pub type TableSpec<T> = Vec<Vec<T>>;
 
 /// Type alias for output table layout with spanned cells rendered as `None`.
-pub(crate) type TableLayout<T> = Vec<Vec<Option<T>>>;
+pub type TableLayout<T> = Vec<Vec<Option<T>>>;
 
 
 /// [PRIVATE] Tracks which columns are currently occupied by active row
@@ -72,10 +148,22 @@ impl RowSpanTracker {
 
     /// Track a new rowspan for the given column.  Caller should provide
     /// the total number of spanned rows for the column.
-    fn track(&mut self, col_index: usize, row_count: usize) {
+    ///
+    /// Returns `TableError::OverlappingSpans` if the column is already
+    /// claimed by an active rowspan from a different master cell — this is
+    /// a safety net for `FloorPlanner`s that don't already guard placement
+    /// with `cell_fits`.
+    fn track(&mut self, row: usize, col_index: usize, row_count: usize) -> Result<(), TableError> {
+        if self.spanned(col_index) {
+            return Err(TableError::OverlappingSpans {
+                row,
+                col: col_index,
+            });
+        }
         if row_count > 1 {
             self.0.insert(col_index, row_count);
         }
+        Ok(())
     }
 
     /// Decrement all the active spans.  This should be called after each
@@ -118,40 +206,291 @@ fn cell_fits(col: usize, col_count: usize, active_row_spans: &RowSpanTracker) ->
     true
 }
 
-/// Determine the layout of table cells given the available spans and the
-/// data for the table.
-pub(crate) fn layout_table<T>(spaninfo: &HashMap<T, Span>, data: &TableSpec<T>) -> TableLayout<T>
+/// A pluggable placement strategy: decides which grid position each cell of
+/// a `TableSpec` lands in, given the spans it needs to accommodate.
+pub trait FloorPlanner<T> {
+    /// Lay out `data` according to `spaninfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TableError::RaggedInput` if a row of a multi-row table is
+    /// empty while no rowspan from an earlier row is still active, and
+    /// `TableError::OverlappingSpans` if two
+    /// different master cells' spans would both claim the same grid
+    /// position.
+    fn place(
+        &self,
+        spaninfo: &HashMap<T, Span>,
+        data: &TableSpec<T>,
+    ) -> Result<TableLayout<T>, TableError>;
+}
+
+/// The original placement strategy: cells are placed strictly left to
+/// right, and a span that doesn't fit at the write head is pushed rightward
+/// past whatever blocks it.
+#[derive(Default)]
+pub struct GreedyPlanner;
+
+impl<T> FloorPlanner<T> for GreedyPlanner
+where
+    T: Hash + Eq + Clone,
+{
+    fn place(
+        &self,
+        spaninfo: &HashMap<T, Span>,
+        data: &TableSpec<T>,
+    ) -> Result<TableLayout<T>, TableError> {
+        let mut table: TableLayout<T> = Vec::new();
+        let mut active_row_spans = RowSpanTracker::new();
+        for (row_index, inrow) in data.iter().enumerate() {
+            if inrow.is_empty() && data.len() > 1 {
+                // A row with no new cells is accepted while some rowspan
+                // from above is still active (as in a full-width vertical
+                // merge), and padded with `None`s up to the highest spanned
+                // column; whether every column is covered is not checked.
+                // With no active rowspan it's genuinely ragged input.
+                match active_row_spans.max_spanned() {
+                    Some(col) => {
+                        table.push(vec![None; col + 1]);
+                        active_row_spans.dec();
+                        continue;
+                    }
+                    None => return Err(TableError::RaggedInput),
+                }
+            }
+
+            let mut row = Vec::new();
+            for cell in inrow.iter() {
+                let span = spaninfo.get(cell).cloned().unwrap_or_default();
+
+                while !cell_fits(row.len(), span.cols, &active_row_spans) {
+                    row.push(None);
+                }
+
+                active_row_spans.track(row_index, row.len(), span.rows)?;
+                row.push(Some(cell.clone()));
+                for _ in 1..span.cols {
+                    active_row_spans.track(row_index, row.len(), span.rows)?;
+                    row.push(None);
+                }
+            }
+            table.push(row);
+            active_row_spans.dec();
+        }
+
+        // Handle trailing spanned rows.
+        while let Some(col) = active_row_spans.max_spanned() {
+            table.push(vec![None; col + 1]);
+            active_row_spans.dec();
+        }
+        Ok(table)
+    }
+}
+
+/// An alternative placement strategy: instead of always skipping forward
+/// past a blocking span, each cell probes for the first free gap starting
+/// from the left edge of the row -- including gaps to the left of cells
+/// already placed in this row -- and is written there instead. This can
+/// pack a row into fewer columns than `GreedyPlanner`, at the cost of
+/// cells no longer necessarily appearing in column order that matches
+/// their order in the input row.
+#[derive(Default)]
+pub struct CompactPlanner;
+
+impl<T> FloorPlanner<T> for CompactPlanner
 where
     T: Hash + Eq + Clone,
 {
-    let mut table: TableLayout<T> = Vec::new();
-    let mut active_row_spans = RowSpanTracker::new();
-    for inrow in data {
-        let mut row = Vec::new();
-        for cell in inrow.iter() {
-            let span = spaninfo.get(&cell).cloned().unwrap_or_default();
-
-            while !cell_fits(row.len(), span.cols, &active_row_spans) {
-                row.push(None);
+    fn place(
+        &self,
+        spaninfo: &HashMap<T, Span>,
+        data: &TableSpec<T>,
+    ) -> Result<TableLayout<T>, TableError> {
+        let mut table: TableLayout<T> = Vec::new();
+        let mut active_row_spans = RowSpanTracker::new();
+        for (row_index, inrow) in data.iter().enumerate() {
+            if inrow.is_empty() && data.len() > 1 {
+                // A row with no new cells is accepted while some rowspan
+                // from above is still active (as in a full-width vertical
+                // merge), and padded with `None`s up to the highest spanned
+                // column; whether every column is covered is not checked.
+                // With no active rowspan it's genuinely ragged input.
+                match active_row_spans.max_spanned() {
+                    Some(col) => {
+                        table.push(vec![None; col + 1]);
+                        active_row_spans.dec();
+                        continue;
+                    }
+                    None => return Err(TableError::RaggedInput),
+                }
             }
 
-            active_row_spans.track(row.len(), span.rows);
-            row.push(Some(cell.clone()));
-            for _ in 1..span.cols {
-                active_row_spans.track(row.len(), span.rows);
-                row.push(None);
+            let mut row: Vec<Option<T>> = Vec::new();
+            let mut occupied: Vec<bool> = Vec::new();
+            for cell in inrow.iter() {
+                let span = spaninfo.get(cell).cloned().unwrap_or_default();
+
+                let mut start = 0;
+                while !gap_fits(start, span.cols, &active_row_spans, &occupied) {
+                    start += 1;
+                }
+                let end = start + span.cols;
+                if row.len() < end {
+                    row.resize(end, None);
+                    occupied.resize(end, false);
+                }
+
+                active_row_spans.track(row_index, start, span.rows)?;
+                row[start] = Some(cell.clone());
+                occupied[start] = true;
+                for offset in 1..span.cols {
+                    active_row_spans.track(row_index, start + offset, span.rows)?;
+                    occupied[start + offset] = true;
+                }
             }
+            table.push(row);
+            active_row_spans.dec();
+        }
+
+        // Handle trailing spanned rows.
+        while let Some(col) = active_row_spans.max_spanned() {
+            table.push(vec![None; col + 1]);
+            active_row_spans.dec();
+        }
+        Ok(table)
+    }
+}
+
+/// [PRIVATE] Like `cell_fits`, but also rules out columns already occupied
+/// by an earlier cell placed in this row, so a gap can only be reused once.
+fn gap_fits(
+    col: usize,
+    col_count: usize,
+    active_row_spans: &RowSpanTracker,
+    occupied: &[bool],
+) -> bool {
+    for peek in col..col + col_count {
+        if active_row_spans.spanned(peek) || occupied.get(peek).copied().unwrap_or(false) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Determine the layout of table cells given the available spans and the
+/// data for the table, using the default `GreedyPlanner` strategy.
+///
+/// # Errors
+///
+/// See `FloorPlanner::place`.
+pub fn layout_table<T>(
+    spaninfo: &HashMap<T, Span>,
+    data: &TableSpec<T>,
+) -> Result<TableLayout<T>, TableError>
+where
+    T: Hash + Eq + Clone,
+{
+    layout_table_with(&GreedyPlanner, spaninfo, data)
+}
+
+/// Determine the layout of table cells using the given `FloorPlanner`
+/// strategy.
+///
+/// # Errors
+///
+/// See `FloorPlanner::place`.
+pub fn layout_table_with<T>(
+    planner: &dyn FloorPlanner<T>,
+    spaninfo: &HashMap<T, Span>,
+    data: &TableSpec<T>,
+) -> Result<TableLayout<T>, TableError> {
+    planner.place(spaninfo, data)
+}
+
+/// Which row-group a section of a `SectionedTable` belongs to, mirroring
+/// HTML's `<thead>`/`<tbody>`/`<tfoot>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Section {
+    Header,
+    Body,
+    Footer,
+}
+
+/// A table built from independently laid-out row groups -- a header, one
+/// or more bodies, and a footer -- normalized against a shared column
+/// count once laid out.
+pub struct SectionedTable<T> {
+    sections: Vec<(Section, TableSpec<T>)>,
+}
+
+impl<T> SectionedTable<T> {
+    /// Create an empty sectioned table.
+    pub fn new() -> SectionedTable<T> {
+        SectionedTable {
+            sections: Vec::new(),
         }
-        table.push(row);
-        active_row_spans.dec();
     }
 
-    // Handle trailing spanned rows.
-    while let Some(col) = active_row_spans.max_spanned() {
-        table.push(vec![None; col + 1]);
-        active_row_spans.dec();
+    /// Append a row group to the table.
+    pub fn push(&mut self, section: Section, rows: TableSpec<T>) {
+        self.sections.push((section, rows));
     }
-    table
+}
+
+impl<T> Default for SectionedTable<T> {
+    fn default() -> SectionedTable<T> {
+        SectionedTable::new()
+    }
+}
+
+/// Lay out a `SectionedTable`, using the default `GreedyPlanner` strategy.
+///
+/// Each section is laid out independently, so an active rowspan in one
+/// section never bleeds into the next. The resulting rows are then padded
+/// with trailing `None`s to a shared column count -- the widest row across
+/// every section -- so a short row doesn't just end early.
+///
+/// # Errors
+///
+/// See `FloorPlanner::place`.
+pub fn layout_sectioned_table<T>(
+    spaninfo: &HashMap<T, Span>,
+    table: &SectionedTable<T>,
+) -> Result<Vec<(Section, TableLayout<T>)>, TableError>
+where
+    T: Hash + Eq + Clone,
+{
+    layout_sectioned_table_with(&GreedyPlanner, spaninfo, table)
+}
+
+/// Lay out a `SectionedTable` using the given `FloorPlanner` strategy. See
+/// `layout_sectioned_table` for the padding and section-isolation
+/// behavior.
+///
+/// # Errors
+///
+/// See `FloorPlanner::place`.
+pub fn layout_sectioned_table_with<T>(
+    planner: &dyn FloorPlanner<T>,
+    spaninfo: &HashMap<T, Span>,
+    table: &SectionedTable<T>,
+) -> Result<Vec<(Section, TableLayout<T>)>, TableError> {
+    let mut layouts = Vec::new();
+    for (section, rows) in &table.sections {
+        layouts.push((*section, layout_table_with(planner, spaninfo, rows)?));
+    }
+
+    let width = layouts
+        .iter()
+        .flat_map(|(_, layout)| layout.iter().map(Vec::len))
+        .max()
+        .unwrap_or(0);
+    for (_, layout) in &mut layouts {
+        for row in layout.iter_mut() {
+            row.resize_with(width, || None);
+        }
+    }
+    Ok(layouts)
 }
 
 #[cfg(test)]
@@ -172,7 +511,7 @@ mod tests {
             vec![Some("D"), Some("E"), Some("F")],
             vec![Some("G"), Some("H"), Some("I")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -186,7 +525,7 @@ mod tests {
             vec![Some("D"), None, Some("E")],
             vec![Some("G"), Some("H"), Some("I")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -206,7 +545,7 @@ mod tests {
             vec![Some("G"), None, Some("H")],
             vec![Some("J"), Some("K"), Some("L")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -229,7 +568,7 @@ mod tests {
             vec![None, None, Some("J"), Some("K"), Some("L")],
             vec![Some("M"), Some("N"), Some("O")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -252,7 +591,7 @@ mod tests {
             vec![Some("J"), Some("K"), None, Some("L")],
             vec![Some("M"), Some("N"), Some("O")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -277,7 +616,7 @@ mod tests {
             vec![Some("J"), Some("K"), None, None, Some("L")],
             vec![Some("M"), Some("N"), Some("O")],
         ];
-        let result = layout_table(&spanspec, &data);
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -292,8 +631,173 @@ mod tests {
             vec![None, None, None],
             vec![None, None],
         ];
+        let result = layout_table(&spanspec, &data).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn span_new_panics_on_zero_dimension() {
+        let result = std::panic::catch_unwind(|| Span::new(0, 2));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn span_try_new_reports_zero_dimension() {
+        assert_eq!(
+            Span::try_new(0, 2),
+            Err(TableError::ZeroSpan { rows: 0, cols: 2 })
+        );
+        assert_eq!(
+            Span::try_new(2, 0),
+            Err(TableError::ZeroSpan { rows: 2, cols: 0 })
+        );
+        assert!(Span::try_new(2, 2).is_ok());
+    }
+
+    #[test]
+    fn test_ragged_input_is_an_error() {
+        let spanspec = HashMap::new();
+        let data = vec![vec!["A", "B", "C"], vec![], vec!["G", "H", "I"]];
         let result = layout_table(&spanspec, &data);
+        assert_eq!(result, Err(TableError::RaggedInput));
+    }
+
+    #[test]
+    fn test_full_row_rowspan_is_not_ragged() {
+        // Every column of the middle row is covered by a rowspan from the
+        // row above, so it legitimately has no new cells of its own -- it
+        // should be padded with `None`s, not rejected as ragged input.
+        let mut spanspec = HashMap::new();
+        spanspec.insert("A", Span::new(2, 1));
+        spanspec.insert("B", Span::new(2, 1));
+        spanspec.insert("C", Span::new(2, 1));
+        let data = vec![vec!["A", "B", "C"], vec![], vec!["D", "E", "F"]];
+        let expected = vec![
+            vec![Some("A"), Some("B"), Some("C")],
+            vec![None, None, None],
+            vec![Some("D"), Some("E"), Some("F")],
+        ];
+        let result = layout_table(&spanspec, &data).unwrap();
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn greedy_planner_matches_layout_table() {
+        let mut spanspec = HashMap::new();
+        spanspec.insert("E", Span::new(2, 1));
+        spanspec.insert("G", Span::new(2, 2));
+        let data = vec![
+            vec!["A", "B", "C"],
+            vec!["D", "E", "F"],
+            vec!["G", "H", "I"],
+            vec!["J", "K", "L"],
+            vec!["M", "N", "O"],
+        ];
+        let expected = layout_table(&spanspec, &data).unwrap();
+        let result = layout_table_with(&GreedyPlanner, &spanspec, &data).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn compact_planner_reuses_earlier_gaps() {
+        // Row 2 enters with column 1 still blocked by E's rowspan, so "G"
+        // (a 2x2 span) can only start at column 2. Unlike GreedyPlanner,
+        // CompactPlanner lets "H" backfill the still-free column 0 instead
+        // of placing it after "G", producing a narrower row.
+        let mut spanspec = HashMap::new();
+        spanspec.insert("E", Span::new(2, 1));
+        spanspec.insert("G", Span::new(2, 2));
+        let data = vec![
+            vec!["A", "B", "C"],
+            vec!["D", "E", "F"],
+            vec!["G", "H", "I"],
+            vec!["J", "K", "L"],
+            vec!["M", "N", "O"],
+        ];
+        let result = layout_table_with(&CompactPlanner, &spanspec, &data).unwrap();
+        assert_eq!(
+            result[2],
+            vec![Some("H"), None, Some("G"), None, Some("I")]
+        );
+        assert!(result[2].len() < layout_table(&spanspec, &data).unwrap()[2].len());
+    }
+
+    #[test]
+    fn sectioned_table_pads_short_rows_to_shared_width() {
+        let spanspec = HashMap::new();
+        let mut table = SectionedTable::new();
+        table.push(Section::Header, vec![vec!["A", "B", "C"]]);
+        table.push(Section::Body, vec![vec!["D", "E"]]);
+        table.push(Section::Footer, vec![vec!["F"]]);
+
+        let result = layout_sectioned_table(&spanspec, &table).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                (Section::Header, vec![vec![Some("A"), Some("B"), Some("C")]]),
+                (Section::Body, vec![vec![Some("D"), Some("E"), None]]),
+                (Section::Footer, vec![vec![Some("F"), None, None]]),
+            ]
+        );
+    }
+
+    #[test]
+    fn sectioned_table_rowspans_do_not_leak_across_sections() {
+        // B's rowspan reaches one row past the end of the body section. If
+        // it leaked into the footer, "D" would be pushed out to column 2
+        // instead of landing directly under "B".
+        let mut spanspec = HashMap::new();
+        spanspec.insert("B", Span::new(2, 1));
+        let mut table = SectionedTable::new();
+        table.push(Section::Body, vec![vec!["A", "B"]]);
+        table.push(Section::Footer, vec![vec!["C", "D"]]);
+
+        let result = layout_sectioned_table(&spanspec, &table).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                (
+                    Section::Body,
+                    vec![vec![Some("A"), Some("B")], vec![None, None]]
+                ),
+                (Section::Footer, vec![vec![Some("C"), Some("D")]]),
+            ]
+        );
+    }
+
+    /// A `FloorPlanner` that places cells without pre-screening for
+    /// collisions, to exercise `RowSpanTracker::track`'s own
+    /// `OverlappingSpans` safety net.
+    struct OverlappingPlanner;
+
+    impl<T: Hash + Eq + Clone> FloorPlanner<T> for OverlappingPlanner {
+        fn place(
+            &self,
+            spaninfo: &HashMap<T, Span>,
+            data: &TableSpec<T>,
+        ) -> Result<TableLayout<T>, TableError> {
+            let mut table: TableLayout<T> = Vec::new();
+            let mut active_row_spans = RowSpanTracker::new();
+            for (row_index, inrow) in data.iter().enumerate() {
+                let mut row = Vec::new();
+                for cell in inrow.iter() {
+                    let span = spaninfo.get(cell).cloned().unwrap_or_default();
+                    active_row_spans.track(row_index, row.len(), span.rows)?;
+                    row.push(Some(cell.clone()));
+                }
+                table.push(row);
+                active_row_spans.dec();
+            }
+            Ok(table)
+        }
+    }
+
+    #[test]
+    fn overlapping_spans_are_reported_with_coordinates() {
+        let mut spanspec = HashMap::new();
+        spanspec.insert("A", Span::new(2, 1));
+        let data = vec![vec!["A", "B"], vec!["C", "D"]];
+        let result = layout_table_with(&OverlappingPlanner, &spanspec, &data);
+        assert_eq!(result, Err(TableError::OverlappingSpans { row: 1, col: 0 }));
+    }
 }
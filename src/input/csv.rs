@@ -0,0 +1,202 @@
+//! CSV ingestion: parse a CSV document into a rectangular grid of cells,
+//! then optionally detect merged rectangles from runs of blank cells.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::engine::{Span, TableSpec};
+
+/// Controls how `read_csv` infers merged cells from blank runs.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Take every cell literally; blank cells are ordinary (possibly
+    /// repeated) values, and no spans are inferred.
+    Literal,
+    /// Treat a run of blank cells to the right of, or below, a filled cell
+    /// as that cell's merge continuation.
+    BlankRuns,
+}
+
+/// An error parsing a CSV document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// A quoted field was never closed.
+    UnterminatedQuote,
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::UnterminatedQuote => write!(f, "unterminated quoted field"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Parse `data` as CSV and build a `TableSpec`/`spaninfo` pair, inferring
+/// merges according to `policy`.
+pub fn read_csv(
+    data: &str,
+    policy: MergePolicy,
+) -> Result<(TableSpec<String>, HashMap<String, Span>), CsvError> {
+    let grid = parse_csv(data)?;
+    Ok(match policy {
+        MergePolicy::Literal => (grid, HashMap::new()),
+        MergePolicy::BlankRuns => detect_merges(grid),
+    })
+}
+
+/// Split a CSV document into a rectangular grid of fields, padding short
+/// rows with empty strings.
+///
+/// Records are split on line boundaries, so a quoted field can't contain an
+/// embedded newline.
+fn parse_csv(data: &str) -> Result<Vec<Vec<String>>, CsvError> {
+    let mut rows = Vec::new();
+    for line in data.lines() {
+        rows.push(parse_record(line)?);
+    }
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, String::new());
+    }
+    Ok(rows)
+}
+
+/// Split a single CSV record into fields, honoring double-quoted fields
+/// with `""`-escaped quotes, the inverse of `render::CsvRenderer`'s
+/// `quote_field`.
+fn parse_record(line: &str) -> Result<Vec<String>, CsvError> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(ch);
+            }
+        } else if ch == '"' && field.is_empty() {
+            in_quotes = true;
+        } else if ch == ',' {
+            fields.push(std::mem::take(&mut field));
+        } else {
+            field.push(ch);
+        }
+    }
+    if in_quotes {
+        return Err(CsvError::UnterminatedQuote);
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// Detect merged rectangles from runs of blank cells: a run of empty cells
+/// to the right of a filled cell belongs to its colspan, and a run of empty
+/// rows beneath it, across the full colspan width, belongs to its rowspan.
+fn detect_merges(grid: Vec<Vec<String>>) -> (TableSpec<String>, HashMap<String, Span>) {
+    let height = grid.len();
+    let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    // `claimed[r][c]` is true once (r, c) has been attributed to some
+    // anchor cell's span, whether via its colspan or its rowspan.
+    let mut claimed = vec![vec![false; width]; height];
+    let mut spaninfo = HashMap::new();
+    let mut tablespec = vec![Vec::new(); height];
+
+    for r in 0..height {
+        for c in 0..width {
+            if claimed[r][c] || grid[r][c].is_empty() {
+                continue;
+            }
+            let mut colspan = 1;
+            while c + colspan < width
+                && grid[r][c + colspan].is_empty()
+                && !claimed[r][c + colspan]
+            {
+                colspan += 1;
+            }
+            let mut rowspan = 1;
+            while r + rowspan < height
+                && (c..c + colspan)
+                    .all(|cc| grid[r + rowspan][cc].is_empty() && !claimed[r + rowspan][cc])
+            {
+                rowspan += 1;
+            }
+            for row in claimed.iter_mut().skip(r).take(rowspan) {
+                for cell in row.iter_mut().skip(c).take(colspan) {
+                    *cell = true;
+                }
+            }
+            let value = grid[r][c].clone();
+            tablespec[r].push(value.clone());
+            if rowspan > 1 || colspan > 1 {
+                spaninfo.insert(value, Span::new(rowspan, colspan));
+            }
+        }
+    }
+    (tablespec, spaninfo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::layout_table;
+
+    fn strings(rows: &[&[&str]]) -> TableSpec<String> {
+        rows.iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn literal_policy_keeps_blanks_as_ordinary_values() {
+        let (spec, spans) = read_csv("A,B\n,C\n", MergePolicy::Literal).unwrap();
+        assert_eq!(spec, strings(&[&["A", "B"], &["", "C"]]));
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn blank_runs_detects_colspan_and_rowspan() {
+        let (spec, spans) = read_csv("A,,B\nC,D,\nE,F,G\n", MergePolicy::BlankRuns).unwrap();
+        assert_eq!(spec, strings(&[&["A", "B"], &["C", "D"], &["E", "F", "G"]]));
+        assert_eq!(spans.get("A"), Some(&Span::new(1, 2)));
+        assert_eq!(spans.get("B"), Some(&Span::new(2, 1)));
+        assert_eq!(spans.len(), 2);
+    }
+
+    #[test]
+    fn full_width_vertical_merge_round_trips_through_layout_table() {
+        // A completely blank row beneath a fully merged header row is a
+        // routine spreadsheet shape -- it must round-trip through
+        // `layout_table`, not be rejected as ragged input.
+        let (spec, spans) = read_csv("A,B,C\n,,\nD,E,F\n", MergePolicy::BlankRuns).unwrap();
+        assert_eq!(spec, strings(&[&["A", "B", "C"], &[], &["D", "E", "F"]]));
+
+        let layout = layout_table(&spans, &spec).unwrap();
+        assert_eq!(
+            layout,
+            vec![
+                vec![Some("A".to_string()), Some("B".to_string()), Some("C".to_string())],
+                vec![None, None, None],
+                vec![Some("D".to_string()), Some("E".to_string()), Some("F".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_quote_is_an_error() {
+        assert_eq!(
+            read_csv("\"A,B\n", MergePolicy::Literal),
+            Err(CsvError::UnterminatedQuote)
+        );
+    }
+}
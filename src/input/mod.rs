@@ -0,0 +1,11 @@
+//! Build a `TableSpec<T>` and `spaninfo` from external tabular sources,
+//! detecting merged rectangles so `layout_table` can round-trip real-world
+//! sheets.
+
+mod csv;
+#[cfg(feature = "calamine")]
+mod xlsx;
+
+pub use self::csv::{read_csv, CsvError, MergePolicy};
+#[cfg(feature = "calamine")]
+pub use self::xlsx::{read_xlsx, XlsxError};
@@ -0,0 +1,160 @@
+//! XLSX ingestion via `calamine`, using a worksheet's explicit merge ranges
+//! to build `spaninfo` directly, rather than guessing from blank runs.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use calamine::{open_workbook, Data, Dimensions, Range, Reader, Xlsx};
+
+use crate::engine::{Span, TableSpec};
+
+/// An error reading an XLSX workbook.
+#[derive(Debug)]
+pub enum XlsxError {
+    /// The workbook could not be opened or parsed.
+    Workbook(calamine::XlsxError),
+    /// The workbook has no worksheets.
+    NoWorksheet,
+}
+
+impl fmt::Display for XlsxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XlsxError::Workbook(err) => write!(f, "could not read workbook: {}", err),
+            XlsxError::NoWorksheet => write!(f, "workbook has no worksheets"),
+        }
+    }
+}
+
+impl std::error::Error for XlsxError {}
+
+impl From<calamine::XlsxError> for XlsxError {
+    fn from(err: calamine::XlsxError) -> XlsxError {
+        XlsxError::Workbook(err)
+    }
+}
+
+/// Read the first worksheet of the XLSX workbook at `path`, using its
+/// merged cell ranges to build `spaninfo` directly: unlike CSV, the format
+/// records merges explicitly, so there's no blank-run heuristic to apply.
+pub fn read_xlsx<P: AsRef<Path>>(
+    path: P,
+) -> Result<(TableSpec<String>, HashMap<String, Span>), XlsxError> {
+    let mut workbook: Xlsx<_> = open_workbook(path)?;
+    let sheet_name = workbook
+        .sheet_names()
+        .first()
+        .cloned()
+        .ok_or(XlsxError::NoWorksheet)?;
+    let range = workbook.worksheet_range(&sheet_name)?;
+    let merges = workbook
+        .worksheet_merge_cells(&sheet_name)
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(build_tablespec(&range, &merges))
+}
+
+/// Turn a worksheet's values and merge ranges into a `TableSpec`/`spaninfo`
+/// pair, claiming every cell a merge covers and keeping only its anchor
+/// (top-left) cell in the spec.
+///
+/// Split out from `read_xlsx` so this logic can be tested directly against
+/// a hand-built `Range`/merge list, without going through `calamine`'s
+/// file-backed `Reader` trait.
+fn build_tablespec(range: &Range<Data>, merges: &[Dimensions]) -> (TableSpec<String>, HashMap<String, Span>) {
+    let height = range.height();
+    let width = range.width();
+    let mut claimed = vec![vec![false; width]; height];
+    let mut anchor = vec![vec![false; width]; height];
+    let mut spaninfo = HashMap::new();
+
+    for merge in merges {
+        let (r0, c0) = (merge.start.0 as usize, merge.start.1 as usize);
+        let (r1, c1) = (merge.end.0 as usize, merge.end.1 as usize);
+        anchor[r0][c0] = true;
+        for row in claimed.iter_mut().skip(r0).take(r1 - r0 + 1) {
+            for cell in row.iter_mut().skip(c0).take(c1 - c0 + 1) {
+                *cell = true;
+            }
+        }
+        spaninfo.insert(cell_text(range, r0, c0), Span::new(r1 - r0 + 1, c1 - c0 + 1));
+    }
+
+    let mut tablespec = vec![Vec::new(); height];
+    for r in 0..height {
+        for c in 0..width {
+            if !claimed[r][c] || anchor[r][c] {
+                tablespec[r].push(cell_text(range, r, c));
+            }
+        }
+    }
+    (tablespec, spaninfo)
+}
+
+fn cell_text(range: &Range<Data>, row: usize, col: usize) -> String {
+    range
+        .get_value((row as u32, col as u32))
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a `Range<Data>` from string values, leaving `""` entries unset
+    /// -- the shape calamine reports for a merge's non-anchor cells.
+    fn range_from(rows: &[&[&str]]) -> Range<Data> {
+        let height = rows.len();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut range = Range::new((0, 0), ((height - 1) as u32, (width - 1) as u32));
+        for (r, row) in rows.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                if !value.is_empty() {
+                    range.set_value((r as u32, c as u32), Data::String(value.to_string()));
+                }
+            }
+        }
+        range
+    }
+
+    #[test]
+    fn cell_text_reads_a_value_and_defaults_to_empty_when_unset() {
+        let mut range = Range::new((0, 0), (0, 1));
+        range.set_value((0, 0), Data::String("A".to_string()));
+        assert_eq!(cell_text(&range, 0, 0), "A");
+        assert_eq!(cell_text(&range, 0, 1), "");
+    }
+
+    #[test]
+    fn build_tablespec_with_no_merges_keeps_every_cell() {
+        let range = range_from(&[&["A", "B"], &["C", "D"]]);
+        let (spec, spans) = build_tablespec(&range, &[]);
+        assert_eq!(
+            spec,
+            vec![
+                vec!["A".to_string(), "B".to_string()],
+                vec!["C".to_string(), "D".to_string()],
+            ]
+        );
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn build_tablespec_keeps_only_the_anchor_cell_of_a_merge() {
+        let range = range_from(&[&["A", "", "B"], &["", "", "C"]]);
+        let merges = vec![Dimensions::new((0, 0), (1, 1))];
+        let (spec, spans) = build_tablespec(&range, &merges);
+        assert_eq!(
+            spec,
+            vec![
+                vec!["A".to_string(), "B".to_string()],
+                vec!["C".to_string()],
+            ]
+        );
+        assert_eq!(spans.get("A"), Some(&Span::new(2, 2)));
+        assert_eq!(spans.len(), 1);
+    }
+}
@@ -32,5 +32,11 @@ fn main() {
         .get_matches();
     let spaninfo = opts.value_of("SPANINFO").unwrap();
     let tablespec = opts.value_of("TABLESPEC").unwrap();
-    println!("{}", render_json_table(spaninfo, tablespec).unwrap());
+    match render_json_table(spaninfo, tablespec) {
+        Ok(rendered) => println!("{}", rendered),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
 }
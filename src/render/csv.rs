@@ -0,0 +1,60 @@
+//! CSV rendering.
+
+use std::fmt::Display;
+
+use super::Renderer;
+use crate::engine::TableLayout;
+
+/// Renders a `TableLayout` as CSV, with the continuation cells of a span
+/// rendered as empty fields.
+#[derive(Default)]
+pub struct CsvRenderer;
+
+impl<T: Display> Renderer<T> for CsvRenderer {
+    fn render(&self, layout: &TableLayout<T>) -> String {
+        let mut out = String::new();
+        for row in layout {
+            let fields: Vec<String> = row
+                .iter()
+                .map(|cell| match cell {
+                    Some(value) => quote_field(&value.to_string()),
+                    None => String::new(),
+                })
+                .collect();
+            out.push_str(&fields.join(","));
+            out.push_str("\r\n");
+        }
+        out
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline.
+fn quote_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_cells_and_blank_continuations() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), None],
+            vec![Some("B"), Some("C")],
+        ];
+        let rendered = CsvRenderer.render(&layout);
+        assert_eq!(rendered, "A,\r\nB,C\r\n");
+    }
+
+    #[test]
+    fn quotes_fields_containing_commas_quotes_or_newlines() {
+        let layout: TableLayout<&str> = vec![vec![Some("a,b"), Some("say \"hi\""), Some("x\ny")]];
+        let rendered = CsvRenderer.render(&layout);
+        assert_eq!(rendered, "\"a,b\",\"say \"\"hi\"\"\",\"x\ny\"\r\n");
+    }
+}
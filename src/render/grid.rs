@@ -0,0 +1,344 @@
+//! Terminal/Unicode box-drawing rendering.
+//!
+//! Unlike [`HtmlRenderer`](super::HtmlRenderer), this renderer has no access
+//! to the original `spaninfo` -- it reconstructs each cell's rowspan and
+//! colspan purely from the runs of `None` around it in the `TableLayout`.
+
+use std::fmt::Display;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::engine::TableLayout;
+
+/// Border/corner character set used by `render_grid`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum GridStyle {
+    /// Plain `+`, `-`, `|` borders.
+    Ascii,
+    /// Unicode box-drawing characters with rounded corners.
+    Rounded,
+}
+
+/// Horizontal alignment of a column's text within its cell.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// A reconstructed cell: its origin position in the layout, and the
+/// rowspan/colspan it occupies from there.
+struct Cell {
+    row: usize,
+    col: usize,
+    rowspan: usize,
+    colspan: usize,
+    text: String,
+}
+
+/// Draws a `TableLayout` as a bordered grid using box-drawing characters,
+/// with merged cells rendered as a single wide/tall region and no internal
+/// border lines crossing a span.
+pub fn render_grid<T: Display>(
+    layout: &TableLayout<T>,
+    style: GridStyle,
+    alignments: &[Alignment],
+) -> String {
+    let height = layout.len();
+    let width = layout.iter().map(|row| row.len()).max().unwrap_or(0);
+    if height == 0 || width == 0 {
+        return String::new();
+    }
+
+    // Reconstruct each row's cells one row at a time, resolving colspan and
+    // rowspan jointly instead of scanning each axis independently: a `None`
+    // is claimed by a same-row colspan first, and only a leftover `None` --
+    // one no origin in this row reached -- can continue a rowspan from the
+    // cell active in that column above. Resolving colspan first is what
+    // keeps a block-spanning cell (rowspan *and* colspan > 1) from having
+    // its own colspan continuation mistaken for a neighbour's rowspan. In
+    // turn, a `None` directly right of a cell is always read as that cell's
+    // colspan, even where it continues a rowspan from the row above.
+    let mut cells: Vec<Cell> = Vec::new();
+    let mut active: Vec<Option<usize>> = vec![None; width];
+    for (r, inrow) in layout.iter().enumerate() {
+        let mut claimed_this_row: Vec<Option<usize>> = vec![None; width];
+        let mut c = 0;
+        while c < width {
+            if let Some(value) = inrow.get(c).and_then(Option::as_ref) {
+                let mut colspan = 1;
+                while c + colspan < width
+                    && claimed_this_row[c + colspan].is_none()
+                    && matches!(inrow.get(c + colspan), Some(None))
+                {
+                    colspan += 1;
+                }
+                let idx = cells.len();
+                cells.push(Cell {
+                    row: r,
+                    col: c,
+                    rowspan: 1,
+                    colspan,
+                    text: value.to_string(),
+                });
+                for slot in claimed_this_row.iter_mut().skip(c).take(colspan) {
+                    *slot = Some(idx);
+                }
+                c += colspan;
+            } else {
+                c += 1;
+            }
+        }
+
+        let mut next_active = vec![None; width];
+        for c in 0..width {
+            if let Some(idx) = claimed_this_row[c] {
+                next_active[c] = Some(idx);
+            } else if let Some(idx) = active[c] {
+                if matches!(inrow.get(c), Some(None)) {
+                    if c == 0 || active[c - 1] != Some(idx) {
+                        cells[idx].rowspan += 1;
+                    }
+                    next_active[c] = Some(idx);
+                }
+            }
+        }
+        active = next_active;
+    }
+
+    // Every position starts out with its own unique placeholder owner, so
+    // two unrelated blanks -- whether a real `None` or a short row padded
+    // out to `width` -- never compare equal and suppress a border between
+    // them; only positions inside the same reconstructed `Cell` share an
+    // owner.
+    let mut owner: Vec<Vec<usize>> = (0..height)
+        .map(|r| (0..width).map(|c| cells.len() + r * width + c).collect())
+        .collect();
+    for (idx, cell) in cells.iter().enumerate() {
+        for row in owner.iter_mut().skip(cell.row).take(cell.rowspan) {
+            for slot in row.iter_mut().skip(cell.col).take(cell.colspan) {
+                *slot = idx;
+            }
+        }
+    }
+
+    // Column widths: the widest single-column cell in that column, widened
+    // further if a merged cell's text doesn't otherwise fit.
+    let mut colwidth = vec![0usize; width];
+    for cell in &cells {
+        if cell.colspan == 1 {
+            colwidth[cell.col] = colwidth[cell.col].max(UnicodeWidthStr::width(cell.text.as_str()));
+        }
+    }
+    for cell in &cells {
+        if cell.colspan > 1 {
+            let available: usize = (cell.col..cell.col + cell.colspan)
+                .map(|c| colwidth[c])
+                .sum::<usize>()
+                + (cell.colspan - 1);
+            let needed = UnicodeWidthStr::width(cell.text.as_str());
+            if needed > available {
+                let last = cell.col + cell.colspan - 1;
+                colwidth[last] += needed - available;
+            }
+        }
+    }
+
+    let (h_char, v_char) = match style {
+        GridStyle::Ascii => ('-', '|'),
+        GridStyle::Rounded => ('─', '│'),
+    };
+
+    // A horizontal segment is present under column `col` at separator slot
+    // `row_slot`, unless it's an internal slot that a rowspan bridges.
+    let h_segment_present =
+        |row_slot: usize, col: usize| row_slot == 0 || row_slot == height || owner[row_slot - 1][col] != owner[row_slot][col];
+    // A vertical segment is present to the right of row `row` at separator
+    // slot `col_slot`, unless it's an internal slot that a colspan bridges.
+    let v_segment_present =
+        |col_slot: usize, row: usize| col_slot == 0 || col_slot == width || owner[row][col_slot - 1] != owner[row][col_slot];
+
+    let junction = |row_slot: usize, col_slot: usize| -> char {
+        let left = col_slot > 0 && h_segment_present(row_slot, col_slot - 1);
+        let right = col_slot < width && h_segment_present(row_slot, col_slot);
+        let up = row_slot > 0 && v_segment_present(col_slot, row_slot - 1);
+        let down = row_slot < height && v_segment_present(col_slot, row_slot);
+        match style {
+            GridStyle::Ascii => {
+                if up || down || left || right {
+                    '+'
+                } else {
+                    ' '
+                }
+            }
+            GridStyle::Rounded => match (up, down, left, right) {
+                (false, false, false, false) => ' ',
+                (true, false, false, false) | (false, true, false, false) | (true, true, false, false) => '│',
+                (false, false, true, false) | (false, false, false, true) | (false, false, true, true) => '─',
+                (false, true, false, true) => '╭',
+                (false, true, true, false) => '╮',
+                (true, false, false, true) => '╰',
+                (true, false, true, false) => '╯',
+                (true, true, false, true) => '├',
+                (true, true, true, false) => '┤',
+                (false, true, true, true) => '┬',
+                (true, false, true, true) => '┴',
+                (true, true, true, true) => '┼',
+            },
+        }
+    };
+
+    let mut out = String::new();
+    #[allow(clippy::needless_range_loop)] // row_slot/col drive border lookups, not a plain scan
+    for row_slot in 0..=height {
+        out.push(junction(row_slot, 0));
+        for col in 0..width {
+            let segment = if h_segment_present(row_slot, col) {
+                h_char
+            } else {
+                ' '
+            };
+            for _ in 0..colwidth[col] {
+                out.push(segment);
+            }
+            out.push(junction(row_slot, col + 1));
+        }
+        out.push('\n');
+
+        if row_slot < height {
+            out.push(v_char);
+            let mut col = 0;
+            while col < width {
+                let align = alignments.get(col).copied().unwrap_or(Alignment::Left);
+                let owner_id = owner[row_slot][col];
+                let origin = cells
+                    .get(owner_id)
+                    .filter(|cell| cell.row == row_slot && cell.col == col);
+                let (text, span) = match origin {
+                    Some(cell) => (cell.text.as_str(), cell.colspan),
+                    None => ("", 1),
+                };
+                let cell_width: usize =
+                    (col..col + span).map(|c| colwidth[c]).sum::<usize>() + (span - 1);
+                out.push_str(&pad(text, cell_width, align));
+                col += span;
+                let sep = if col < width && v_segment_present(col, row_slot) {
+                    v_char
+                } else if col < width {
+                    ' '
+                } else {
+                    v_char
+                };
+                out.push(sep);
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Pad `text` to `width` display columns according to `align`.
+fn pad(text: &str, width: usize, align: Alignment) -> String {
+    let text_width = UnicodeWidthStr::width(text);
+    if text_width >= width {
+        return text.to_string();
+    }
+    let gap = width - text_width;
+    match align {
+        Alignment::Left => format!("{}{}", text, " ".repeat(gap)),
+        Alignment::Right => format!("{}{}", " ".repeat(gap), text),
+        Alignment::Center => {
+            let left = gap / 2;
+            let right = gap - left;
+            format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_simple_rectangular_table() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), Some("B")],
+            vec![Some("C"), Some("D")],
+        ];
+        let rendered = render_grid(&layout, GridStyle::Ascii, &[]);
+        assert_eq!(
+            rendered,
+            "+-+-+\n\
+             |A|B|\n\
+             +-+-+\n\
+             |C|D|\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn merged_cells_suppress_the_internal_border() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), None],
+            vec![Some("B"), Some("C")],
+        ];
+        let rendered = render_grid(&layout, GridStyle::Ascii, &[]);
+        assert_eq!(
+            rendered,
+            "+-+-+\n\
+             |A  |\n\
+             +-+-+\n\
+             |B|C|\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn ragged_rows_do_not_suppress_unrelated_borders() {
+        // Two unrelated short rows, each missing their last column -- before
+        // the owner-id fix, both out-of-range positions compared equal and
+        // suppressed the border between them.
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), Some("B")],
+            vec![Some("C")],
+            vec![Some("D")],
+        ];
+        let rendered = render_grid(&layout, GridStyle::Ascii, &[]);
+        assert_eq!(
+            rendered,
+            "+-+-+\n\
+             |A|B|\n\
+             +-+-+\n\
+             |C| |\n\
+             +-+-+\n\
+             |D| |\n\
+             +-+-+\n"
+        );
+    }
+
+    #[test]
+    fn block_span_colspan_and_rowspan_together_do_not_bleed_into_neighbours() {
+        // "D" spans 2 rows and 2 columns at once. Before resolving colspan
+        // and rowspan jointly, the per-column rowspan scan mistook (1, 1) --
+        // D's own colspan continuation -- for a rowspan of "B", and then
+        // D's colspan scan, finding (1, 1) already "covered" by that bogus
+        // rowspan, stopped short and lost its own second column.
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), Some("B"), Some("C")],
+            vec![Some("D"), None, Some("E")],
+            vec![None, None, Some("F")],
+        ];
+        let rendered = render_grid(&layout, GridStyle::Ascii, &[]);
+        assert_eq!(
+            rendered,
+            "+-+-+-+\n\
+             |A|B|C|\n\
+             +-+-+-+\n\
+             |D  |E|\n\
+             +   +-+\n\
+             |   |F|\n\
+             +-+-+-+\n"
+        );
+    }
+}
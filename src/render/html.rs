@@ -0,0 +1,101 @@
+//! HTML rendering: emits a `<table>` where merged cells carry `rowspan`/
+//! `colspan` attributes and continuation cells are omitted entirely.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+use super::Renderer;
+use crate::engine::{Span, TableLayout};
+
+/// Renders a `TableLayout` as an HTML `<table>`.
+///
+/// The `None`s in a `TableLayout` don't say which master cell they
+/// continue, so this renderer is handed the original `spaninfo` and looks
+/// up each `Some` cell's true `rowspan`/`colspan` by value, defaulting to
+/// 1x1 when the cell has no entry.
+pub struct HtmlRenderer<'a, T> {
+    spaninfo: &'a HashMap<T, Span>,
+}
+
+impl<'a, T> HtmlRenderer<'a, T> {
+    /// Create a renderer that looks up span sizes in `spaninfo`.
+    pub fn new(spaninfo: &'a HashMap<T, Span>) -> Self {
+        HtmlRenderer { spaninfo }
+    }
+}
+
+impl<'a, T> Renderer<T> for HtmlRenderer<'a, T>
+where
+    T: Display + Eq + Hash,
+{
+    fn render(&self, layout: &TableLayout<T>) -> String {
+        let mut out = String::from("<table>\n");
+        for row in layout {
+            out.push_str("  <tr>\n");
+            for value in row.iter().flatten() {
+                let span = self.spaninfo.get(value).copied().unwrap_or_default();
+                let mut attrs = String::new();
+                if span.rows() > 1 {
+                    attrs.push_str(&format!(" rowspan=\"{}\"", span.rows()));
+                }
+                if span.cols() > 1 {
+                    attrs.push_str(&format!(" colspan=\"{}\"", span.cols()));
+                }
+                out.push_str(&format!("    <td{}>{}</td>\n", attrs, escape_html(&value.to_string())));
+            }
+            out.push_str("  </tr>\n");
+        }
+        out.push_str("</table>\n");
+        out
+    }
+}
+
+/// Escape text for safe placement inside an HTML element body.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_simple_rectangular_table() {
+        let layout: TableLayout<&str> = vec![vec![Some("A"), Some("B")]];
+        let spaninfo = HashMap::new();
+        let rendered = HtmlRenderer::new(&spaninfo).render(&layout);
+        assert_eq!(
+            rendered,
+            "<table>\n  <tr>\n    <td>A</td>\n    <td>B</td>\n  </tr>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn merged_cells_carry_rowspan_and_colspan_and_omit_continuations() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), None],
+            vec![None, None],
+        ];
+        let mut spaninfo = HashMap::new();
+        spaninfo.insert("A", Span::new(2, 2));
+        let rendered = HtmlRenderer::new(&spaninfo).render(&layout);
+        assert_eq!(
+            rendered,
+            "<table>\n  <tr>\n    <td rowspan=\"2\" colspan=\"2\">A</td>\n  </tr>\n  <tr>\n  </tr>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn cell_text_is_escaped_so_it_cannot_break_out_of_the_td() {
+        let layout: TableLayout<&str> = vec![vec![Some("<script>alert(1)</script> & \"co\"")]];
+        let spaninfo = HashMap::new();
+        let rendered = HtmlRenderer::new(&spaninfo).render(&layout);
+        assert_eq!(
+            rendered,
+            "<table>\n  <tr>\n    <td>&lt;script&gt;alert(1)&lt;/script&gt; &amp; \"co\"</td>\n  </tr>\n</table>\n"
+        );
+    }
+}
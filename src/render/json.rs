@@ -0,0 +1,31 @@
+//! JSON rendering: the layout as a 2D array, with spanned cells represented
+//! as `null`.
+
+/// Renders a `TableLayout` as a JSON 2D array, using `null` for the
+/// continuation cells of a span.
+#[derive(Default)]
+pub struct JsonRenderer;
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> super::Renderer<T> for JsonRenderer {
+    fn render(&self, layout: &crate::engine::TableLayout<T>) -> String {
+        serde_json::to_string(layout).expect("TableLayout of Serialize values should serialize")
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::engine::TableLayout;
+    use crate::render::Renderer;
+
+    #[test]
+    fn renders_cells_and_null_continuations() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), None],
+            vec![Some("B"), Some("C")],
+        ];
+        let rendered = JsonRenderer.render(&layout);
+        assert_eq!(rendered, r#"[["A",null],["B","C"]]"#);
+    }
+}
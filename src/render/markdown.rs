@@ -0,0 +1,75 @@
+//! Markdown (GitHub-flavored) table rendering.
+
+use std::fmt::Display;
+
+use super::Renderer;
+use crate::engine::TableLayout;
+
+/// Renders a `TableLayout` as a GitHub-flavored Markdown table.
+///
+/// Markdown tables have no notion of merged cells, so the continuation
+/// cells of a span are rendered empty, and the first row is treated as the
+/// header.
+#[derive(Default)]
+pub struct MarkdownRenderer;
+
+impl<T: Display> Renderer<T> for MarkdownRenderer {
+    fn render(&self, layout: &TableLayout<T>) -> String {
+        let width = layout.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut out = String::new();
+        for (index, row) in layout.iter().enumerate() {
+            out.push('|');
+            // Pad rows shorter than `width` -- `layout_table` doesn't
+            // guarantee a rectangular grid -- so every row has the same
+            // cell count as the separator row.
+            for col in 0..width {
+                match row.get(col) {
+                    Some(Some(value)) => {
+                        out.push_str(&format!(" {} |", escape_pipes(&value.to_string())))
+                    }
+                    _ => out.push_str("  |"),
+                }
+            }
+            out.push('\n');
+            if index == 0 {
+                out.push('|');
+                for _ in 0..width {
+                    out.push_str(" --- |");
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+/// Escape `|` so cell text can't be mistaken for an extra column boundary.
+fn escape_pipes(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_short_rows_to_the_shared_column_count() {
+        let layout: TableLayout<&str> = vec![
+            vec![Some("A"), Some("B"), Some("C")],
+            vec![Some("D")],
+        ];
+        let rendered = MarkdownRenderer.render(&layout);
+        let mut lines = rendered.lines();
+        assert_eq!(lines.next(), Some("| A | B | C |"));
+        assert_eq!(lines.next(), Some("| --- | --- | --- |"));
+        assert_eq!(lines.next(), Some("| D |  |  |"));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn embedded_pipes_are_escaped_so_the_column_count_does_not_drift() {
+        let layout: TableLayout<&str> = vec![vec![Some("a|b")]];
+        let rendered = MarkdownRenderer.render(&layout);
+        assert_eq!(rendered.lines().next(), Some("| a\\|b |"));
+    }
+}
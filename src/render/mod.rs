@@ -0,0 +1,26 @@
+//! Turn a `TableLayout<T>` into some concrete output representation.
+//!
+//! [`Renderer`] is implemented once per output format. Text formats need
+//! `T: Display` ([`HtmlRenderer`] also needs `T: Eq + Hash` to look cells up
+//! in its `spaninfo`); [`JsonRenderer`] requires `T: Serialize`, gated
+//! behind the `serde` feature.
+
+mod csv;
+mod grid;
+mod html;
+mod json;
+mod markdown;
+
+pub use self::csv::CsvRenderer;
+pub use self::grid::{render_grid, Alignment, GridStyle};
+pub use self::html::HtmlRenderer;
+pub use self::json::JsonRenderer;
+pub use self::markdown::MarkdownRenderer;
+
+use crate::engine::TableLayout;
+
+/// Converts a `TableLayout<T>` into a `String` in some output format.
+pub trait Renderer<T> {
+    /// Render the given table layout.
+    fn render(&self, layout: &TableLayout<T>) -> String;
+}